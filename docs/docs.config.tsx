@@ -116,6 +116,9 @@ export default defineDocs({
         { slug: "table-explorer" },
         { slug: "query-editor" },
         { slug: "erd-viewer" },
+        { slug: "object-catalog" },
+        { slug: "schema-diff" },
+        { slug: "schema-snapshots" },
       ],
     },
   ],
@@ -49,6 +49,8 @@
             pkgs.pkg-config
           ];
           nativeBuildInputs = [ pkgs.openssl ];
+          # libssh2 links the OpenSSL above instead of building its own.
+          OPENSSL_NO_VENDOR = "1";
         };
 
         ui = pkgs.buildNpmPackage {
@@ -10,12 +10,13 @@ const BASE_URL = import.meta.env.PROD
     : "/api"
   : "http://localhost:3030/api";
 
-const counts = z
-  .object({
-    name: z.string(),
-    count: z.number(),
-  })
-  .array();
+const count = z.object({
+  name: z.string(),
+  count: z.number(),
+  approximate: z.boolean().default(false),
+});
+
+const counts = count.array();
 
 const overview = z.object({
   file_name: z.string(),
@@ -48,6 +49,7 @@ const table = z.object({
   name: z.string(),
   sql: z.string().nullable(),
   row_count: z.number(),
+  row_count_approximate: z.boolean(),
   index_count: z.number(),
   column_count: z.number(),
   table_size: z.string(),
@@ -63,16 +65,55 @@ const query = z.object({
   rows: z.any().array().array(),
 });
 
+const sessionQuery = query.extend({
+  in_transaction: z.boolean(),
+});
+
+const session = z.object({
+  id: z.string(),
+  in_transaction: z.boolean(),
+});
+
 const metadata = z.object({
   version: z.string(),
   can_shutdown: z.boolean(),
+  refreshed_at: z
+    .string()
+    .datetime()
+    .transform((x) => new Date(x))
+    .nullable(),
+  connection: z.enum(["connecting", "connected", "reconnecting"]),
 });
 
 const autocomplete = z.object({
   tables: z
     .object({
-      columns: z.string().array(),
       table_name: z.string(),
+      view: z.boolean(),
+      columns: z
+        .object({
+          name: z.string(),
+          data_type: z.string(),
+        })
+        .array(),
+    })
+    .array(),
+  schemas: z.string().array(),
+  functions: z
+    .object({
+      name: z.string(),
+      user_defined: z.boolean(),
+    })
+    .array(),
+  keywords: z.string().array(),
+});
+
+const completions = z.object({
+  completions: z
+    .object({
+      label: z.string(),
+      kind: z.enum(["table", "view", "column", "schema", "function", "keyword"]),
+      detail: z.string().nullable(),
     })
     .array(),
 });
@@ -82,9 +123,14 @@ const $fetch = createZodFetcher();
 export const fetchOverview = () => $fetch(overview, `${BASE_URL}/`);
 export const fetchTables = () => $fetch(tables, `${BASE_URL}/tables`);
 export const fetchTable = (name: string) =>
-  $fetch(table, `${BASE_URL}/tables/${name}`);
+  $fetch(table, `${BASE_URL}/tables/${encodeURIComponent(name)}`);
+export const fetchRowCount = (name: string) =>
+  $fetch(count, `${BASE_URL}/tables/${encodeURIComponent(name)}/count`);
 export const fetchTableData = (name: string, page: number) =>
-  $fetch(tableData, `${BASE_URL}/tables/${name}/data?page=${page}`);
+  $fetch(
+    tableData,
+    `${BASE_URL}/tables/${encodeURIComponent(name)}/data?page=${page}`,
+  );
 export const fetchQuery = (value: string) =>
   $fetch(query, `${BASE_URL}/query`, {
     method: "POST",
@@ -94,9 +140,80 @@ export const fetchQuery = (value: string) =>
     },
     body: JSON.stringify({ query: value }),
   });
+
+/** The session was closed, by its idle timeout or a server restart. */
+export class SessionExpired extends Error {}
+
+const sessionFetch = async <T extends z.ZodType>(
+  schema: T,
+  url: string,
+  init?: RequestInit,
+): Promise<z.infer<T>> => {
+  const res = await fetch(url, init);
+  if (res.status === 404) throw new SessionExpired();
+  if (!res.ok) throw new Error(await res.text());
+  return schema.parse(await res.json());
+};
+
+export const closeSession = (id: string) =>
+  fetch(`${BASE_URL}/sessions/${id}`, { method: "DELETE", keepalive: true });
+
+let opening: Promise<string | null> | null = null;
+
+/**
+ * Opens the session of this tab, closed again when the tab is. Resolves to
+ * `null` if the database doesn't support sessions.
+ */
+export const openSession = () => {
+  opening ??= fetch(`${BASE_URL}/sessions`, { method: "POST" })
+    .then(async (res) => {
+      if (res.status === 501) return null;
+      if (!res.ok) throw new Error(await res.text());
+      const { id } = session.parse(await res.json());
+      window.addEventListener("pagehide", () => closeSession(id), {
+        once: true,
+      });
+      return id;
+    })
+    .finally(() => {
+      opening = null;
+    });
+  return opening;
+};
+export const fetchSession = (id: string) =>
+  sessionFetch(session, `${BASE_URL}/sessions/${id}`);
+export const fetchSessionQuery = (id: string, value: string) =>
+  sessionFetch(sessionQuery, `${BASE_URL}/sessions/${id}/query`, {
+    method: "POST",
+    headers: {
+      Accept: "application/json",
+      "Content-Type": "application/json",
+    },
+    body: JSON.stringify({ query: value }),
+  });
+export const sendTransaction = (
+  id: string,
+  action: "begin" | "commit" | "rollback",
+) =>
+  sessionFetch(session, `${BASE_URL}/sessions/${id}/${action}`, {
+    method: "POST",
+  });
+
 export const fetchMetadata = () => $fetch(metadata, `${BASE_URL}/metadata`);
 export const fetchAutocomplete = () =>
   $fetch(autocomplete, `${BASE_URL}/autocomplete`);
+export const fetchCompletions = (sql: string, cursor: number) =>
+  $fetch(completions, `${BASE_URL}/complete`, {
+    method: "POST",
+    headers: {
+      Accept: "application/json",
+      "Content-Type": "application/json",
+    },
+    body: JSON.stringify({ sql, cursor }),
+  });
+
+export const sendRefresh = () =>
+  fetch(`${BASE_URL}/refresh`, { method: "POST" });
 
 export const sendShutdown = () =>
   fetch(`${BASE_URL}/shutdown`, { method: "POST" });
@@ -111,13 +228,19 @@ const erdColumn = z.object({
 const erdTable = z.object({
   name: z.string(),
   columns: erdColumn.array(),
+  unique_keys: z.string().array().array(),
 });
 
 const erdRelationship = z.object({
+  name: z.string().nullable(),
   from_table: z.string(),
-  from_column: z.string(),
+  from_columns: z.string().array(),
   to_table: z.string(),
-  to_column: z.string(),
+  to_columns: z.string().array(),
+  on_update: z.string().nullable(),
+  on_delete: z.string().nullable(),
+  cardinality: z.enum(["one_to_one", "many_to_one"]),
+  optional: z.boolean(),
 });
 
 const erd = z.object({
@@ -125,4 +248,13 @@ const erd = z.object({
   relationships: erdRelationship.array(),
 });
 
-export const fetchErd = () => $fetch(erd, `${BASE_URL}/erd`);
+export const fetchErd = ({
+  tables,
+  depth,
+}: { tables?: string; depth?: number } = {}) => {
+  const params = new URLSearchParams();
+  if (tables) params.set("tables", tables);
+  if (depth !== undefined) params.set("depth", depth.toString());
+  const query = params.toString();
+  return $fetch(erd, `${BASE_URL}/erd${query ? `?${query}` : ""}`);
+};
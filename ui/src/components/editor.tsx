@@ -10,9 +10,8 @@ import {
   autoSuggestionCompletionItems,
 } from "./editor.config";
 
-import { fetchAutocomplete } from "@/api";
+import { fetchCompletions } from "@/api";
 import { Card } from "@/components/ui/card";
-import { useQuery } from "@tanstack/react-query";
 import { useTheme } from "@/provider/theme.provider";
 import { useSqlFormattingProviders } from "@/lib/sql-formatting";
 
@@ -26,11 +25,6 @@ export const Editor: FunctionComponent<Props> = ({ value, onChange }) => {
   const monacoInstance = useMonaco();
   const providerRef = useRef<IDisposable | null>(null);
 
-  const { data: autoCompleteData } = useQuery({
-    queryKey: ["autocomplete"],
-    queryFn: () => fetchAutocomplete(),
-  });
-
   // Configure Monaco
   useEffect(() => {
     if (!monacoInstance) return;
@@ -50,13 +44,23 @@ export const Editor: FunctionComponent<Props> = ({ value, onChange }) => {
 
   // Register completion provider
   useEffect(() => {
-    if (!monacoInstance || !autoCompleteData) return;
+    if (!monacoInstance) return;
 
     providerRef.current?.dispose();
 
+    const kinds = {
+      table: monacoInstance.languages.CompletionItemKind.Class,
+      view: monacoInstance.languages.CompletionItemKind.Interface,
+      column: monacoInstance.languages.CompletionItemKind.Field,
+      schema: monacoInstance.languages.CompletionItemKind.Module,
+      function: monacoInstance.languages.CompletionItemKind.Function,
+      keyword: monacoInstance.languages.CompletionItemKind.Keyword,
+    };
+
     providerRef.current =
       monacoInstance.languages.registerCompletionItemProvider(ID_LANGUAGE_SQL, {
-        provideCompletionItems: (model, position) => {
+        triggerCharacters: ["."],
+        provideCompletionItems: async (model, position) => {
           const word = model.getWordUntilPosition(position);
           const range = {
             startLineNumber: position.lineNumber,
@@ -64,58 +68,31 @@ export const Editor: FunctionComponent<Props> = ({ value, onChange }) => {
             startColumn: word.startColumn,
             endColumn: word.endColumn,
           };
-          const { suggestions } = autoSuggestionCompletionItems(range);
-
-          const tableColumnSuggestions = autoCompleteData.tables.flatMap(
-            ({ table_name, columns }) => {
-              const alias = table_name.substring(0, 3);
-
-              const table = {
-                label: table_name,
-                kind: monacoInstance.languages.CompletionItemKind.Variable,
-                insertText: table_name,
-                range,
-              };
-
-              const aliasTable = {
-                label: `${table_name} AS ${alias}`,
-                kind: monacoInstance.languages.CompletionItemKind.Variable,
-                insertText: `${table_name} AS ${alias}`,
-                range,
-              };
-
-              const col = columns.map((column) => ({
-                label: column,
-                kind: monacoInstance.languages.CompletionItemKind.Variable,
-                insertText: column,
-                range,
-              }));
-
-              const tableColumn = columns.map((column) => ({
-                label: `${table_name}.${column}`,
-                kind: monacoInstance.languages.CompletionItemKind.Variable,
-                insertText: `${table_name}.${column}`,
-                range,
-              }));
-
-              const tableColumnAlias = columns.map((column) => ({
-                label: `${alias}.${column}`,
-                kind: monacoInstance.languages.CompletionItemKind.Variable,
-                insertText: `${alias}.${column}`,
-                range,
-              }));
-
-              return [
-                table,
-                aliasTable,
-                ...col,
-                ...tableColumn,
-                ...tableColumnAlias,
-              ];
-            },
+          const snippets = autoSuggestionCompletionItems(
+            range,
+          ).suggestions.filter(
+            ({ kind }) =>
+              kind === monacoInstance.languages.CompletionItemKind.Snippet,
+          );
+
+          const { completions } = await fetchCompletions(
+            model.getValue(),
+            model.getOffsetAt(position),
+          );
+
+          const suggestions = completions.map(
+            ({ label, kind, detail }, i) => ({
+              label,
+              kind: kinds[kind],
+              detail: detail ?? undefined,
+              insertText: label,
+              // Keep the server's order: columns in scope first
+              sortText: i.toString().padStart(5, "0"),
+              range,
+            }),
           );
 
-          return { suggestions: [...suggestions, ...tableColumnSuggestions] };
+          return { suggestions: [...suggestions, ...snippets] };
         },
       });
 
@@ -123,7 +100,7 @@ export const Editor: FunctionComponent<Props> = ({ value, onChange }) => {
       providerRef.current?.dispose();
       providerRef.current = null;
     };
-  }, [monacoInstance, autoCompleteData]);
+  }, [monacoInstance]);
 
   useSqlFormattingProviders(monacoInstance);
 
@@ -27,13 +27,19 @@ type Column = {
 type Table = {
   name: string;
   columns: Column[];
+  unique_keys: string[][];
 };
 
 type Relationship = {
+  name: string | null;
   from_table: string;
-  from_column: string;
+  from_columns: string[];
   to_table: string;
-  to_column: string;
+  to_columns: string[];
+  on_update: string | null;
+  on_delete: string | null;
+  cardinality: "one_to_one" | "many_to_one";
+  optional: boolean;
 };
 
 type ErdData = {
@@ -67,8 +73,8 @@ export function ErdDiagram({ data }: Props) {
       id: `edge-${index}`,
       source: rel.from_table,
       target: rel.to_table,
-      sourceHandle: rel.from_column,
-      targetHandle: rel.to_column,
+      sourceHandle: rel.from_columns[0],
+      targetHandle: rel.to_columns[0],
       type: "smoothstep",
       animated: true,
       markerEnd: {
@@ -78,8 +84,11 @@ export function ErdDiagram({ data }: Props) {
       style: {
         stroke: "var(--primary)",
         strokeWidth: 2,
+        strokeDasharray: rel.optional ? "6 4" : undefined,
       },
-      label: `${rel.from_column} → ${rel.to_column}`,
+      label: `${rel.from_columns.join(", ")} → ${rel.to_columns.join(", ")}${
+        rel.cardinality === "one_to_one" ? " (1:1)" : ""
+      }`,
       labelStyle: {
         fill: "var(--muted-foreground)",
         fontSize: 10,
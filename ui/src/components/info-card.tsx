@@ -1,3 +1,4 @@
+import { ReactNode } from "react";
 import { LucideIcon } from "lucide-react";
 import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
 
@@ -6,6 +7,7 @@ export type InfoCardProps = {
   value: string;
   description: string;
   icon: LucideIcon;
+  action?: ReactNode;
 };
 
 export function InfoCard({
@@ -13,6 +15,7 @@ export function InfoCard({
   value,
   description,
   icon: Icon,
+  action,
 }: InfoCardProps) {
   return (
     <Card>
@@ -23,6 +26,7 @@ export function InfoCard({
       <CardContent>
         <div className="text-2xl font-bold">{value}</div>
         <p className="text-xs text-muted-foreground">{description}</p>
+        {action}
       </CardContent>
     </Card>
   );
@@ -7,6 +7,7 @@ import { QueryClient, QueryClientProvider } from "@tanstack/react-query";
 
 import { routeTree } from "./routeTree.gen";
 import { SqlProvider } from "@/provider/sql.provider";
+import { SessionProvider } from "@/provider/session.provider";
 import { ThemeProvider } from "@/provider/theme.provider";
 
 let basePath = document.querySelector<HTMLMetaElement>(
@@ -38,12 +39,14 @@ if (!rootElement.innerHTML) {
   root.render(
     <StrictMode>
       <SqlProvider>
-        <QueryClientProvider client={queryClient}>
-          <ThemeProvider>
-            <RouterProvider router={router} />
-          </ThemeProvider>
-          <ReactQueryDevtools />
-        </QueryClientProvider>
+        <SessionProvider>
+          <QueryClientProvider client={queryClient}>
+            <ThemeProvider>
+              <RouterProvider router={router} />
+            </ThemeProvider>
+            <ReactQueryDevtools />
+          </QueryClientProvider>
+        </SessionProvider>
       </SqlProvider>
     </StrictMode>,
   );
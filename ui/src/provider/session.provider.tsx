@@ -0,0 +1,40 @@
+import createStore from "@/lib/makeStore";
+
+type State = {
+  /** `undefined` until a session is open, `null` if the database has none. */
+  id?: string | null;
+  inTransaction: boolean;
+  /** The server closed the session after it sat idle, rolling back its transaction. */
+  rolledBack: boolean;
+};
+
+type Action =
+  | { type: "OPENED"; data: string | null }
+  | { type: "SET_TRANSACTION"; data: boolean }
+  | { type: "EXPIRED" };
+
+export const {
+  StoreProvider: SessionProvider,
+  useDispatch: useSessionDispatch,
+  useStore: useSession,
+} = createStore(
+  (state: State, action: Action): State => {
+    switch (action.type) {
+      case "OPENED":
+        return { ...state, id: action.data };
+      case "SET_TRANSACTION":
+        return {
+          ...state,
+          inTransaction: action.data,
+          rolledBack: state.rolledBack && !action.data,
+        };
+      case "EXPIRED":
+        return {
+          id: undefined,
+          inTransaction: false,
+          rolledBack: state.inTransaction,
+        };
+    }
+  },
+  { inTransaction: false, rolledBack: false },
+);
@@ -1,10 +1,15 @@
 import React from "react";
-import { Moon, Sun, Power } from "lucide-react";
-import { useQuery } from "@tanstack/react-query";
-import { Link, Outlet, createRootRoute } from "@tanstack/react-router";
+import { Moon, Sun, Power, RefreshCw } from "lucide-react";
+import { useQuery, useQueryClient } from "@tanstack/react-query";
+import {
+  Link,
+  Outlet,
+  createRootRoute,
+  useRouter,
+} from "@tanstack/react-router";
 
 import { cn } from "@/lib/utils";
-import { fetchMetadata, sendShutdown } from "@/api";
+import { fetchMetadata, sendRefresh, sendShutdown } from "@/api";
 import { setTheme, useTheme } from "@/provider/theme.provider";
 
 import {
@@ -45,11 +50,25 @@ export function Root() {
   const theme = useTheme();
   const changeTheme = setTheme();
 
+  const queryClient = useQueryClient();
   const { data } = useQuery({
     queryKey: ["metadata"],
     queryFn: () => fetchMetadata(),
+    refetchInterval: (query) =>
+      query.state.data?.connection === "connected" ? false : 2000,
   });
 
+  const connection = data?.connection;
+  const previous = React.useRef(connection);
+  React.useEffect(() => {
+    if (previous.current !== connection && connection === "connected") {
+      queryClient.invalidateQueries({
+        predicate: (query) => query.queryKey[0] !== "metadata",
+      });
+    }
+    previous.current = connection;
+  }, [connection, queryClient]);
+
   return (
     <>
       <div
@@ -97,9 +116,18 @@ export function Root() {
           </Link>
 
           <div className="flex gap-4 items-center">
+            {connection && connection !== "connected" && (
+              <p
+                className="text-destructive text-xs animate-pulse"
+                title="The database can't be reached, retrying in the background"
+              >
+                {connection === "connecting" ? "Connecting…" : "Reconnecting…"}
+              </p>
+            )}
             <p className="text-primary hidden sm:block text-xs text-right">
               [{data?.version ?? ""}]
             </p>
+            <Refresh refreshedAt={data?.refreshed_at ?? null} />
             {data?.can_shutdown && <Shutdown />}
             <button
               className="text-foreground "
@@ -137,6 +165,32 @@ export function Root() {
   );
 }
 
+function Refresh({ refreshedAt }: { refreshedAt: Date | null }) {
+  const router = useRouter();
+  const queryClient = useQueryClient();
+
+  return (
+    <button
+      className="text-foreground"
+      title={
+        refreshedAt
+          ? `Schema metadata read at ${refreshedAt.toLocaleString()}, click to refresh`
+          : "Refresh schema metadata"
+      }
+      onClick={async () => {
+        await sendRefresh();
+        await queryClient.invalidateQueries();
+        await router.invalidate();
+      }}
+    >
+      <RefreshCw
+        size={28}
+        className="bg-primary/20 text-primary rounded-full p-1"
+      />
+    </button>
+  );
+}
+
 function Shutdown() {
   const currentTheme = useTheme();
 
@@ -1,16 +1,32 @@
 import "react-data-grid/lib/styles.css";
-import { useState } from "react";
+import { useEffect, useState } from "react";
 
 import { z } from "zod";
 import { DataGrid } from "react-data-grid";
-import { useQuery } from "@tanstack/react-query";
+import { useMutation, useQuery } from "@tanstack/react-query";
 import { useDebounce } from "@uidotdev/usehooks";
-import { Database, Play, ShieldX, Terminal } from "lucide-react";
+import {
+  Check,
+  Database,
+  Play,
+  Plus,
+  ShieldX,
+  Terminal,
+  Undo2,
+} from "lucide-react";
 import { createFileRoute } from "@tanstack/react-router";
 
 import { cn } from "@/lib/utils";
-import { fetchQuery } from "@/api";
+import {
+  SessionExpired,
+  fetchQuery,
+  fetchSession,
+  fetchSessionQuery,
+  openSession,
+  sendTransaction,
+} from "@/api";
 import { useSql, useSqlDispatch } from "@/provider/sql.provider";
+import { useSession, useSessionDispatch } from "@/provider/session.provider";
 
 import {
   Card,
@@ -20,6 +36,7 @@ import {
 } from "@/components/ui/card";
 import { Editor } from "@/components/editor";
 import { Toggle } from "@/components/ui/toggle";
+import { Badge } from "@/components/ui/badge";
 import { Button } from "@/components/ui/button";
 import { Skeleton } from "@/components/ui/skeleton";
 import { useTheme } from "@/provider/theme.provider";
@@ -36,17 +53,64 @@ function Query() {
   const setCodeState = useSqlDispatch();
   const code = useDebounce(codeState, 100);
 
+  const session = useSession();
+  const setSession = useSessionDispatch();
+
+  // Every tab runs its queries on a connection of its own, so that transactions
+  // and session settings last from one query to the next.
+  const [attempt, setAttempt] = useState(0);
+  useEffect(() => {
+    if (session.id !== undefined) return;
+    openSession()
+      .then((id) => setSession({ type: "OPENED", data: id }))
+      .catch(() => setTimeout(() => setAttempt((a) => a + 1), 5000));
+  }, [session.id, setSession, attempt]);
+
+  const expired = (e: Error) => {
+    if (e instanceof SessionExpired) setSession({ type: "EXPIRED" });
+  };
+
   const [autoExecute, setAutoExecute] = useState(true);
+  // Half typed statements would fail and, in PostgreSQL, abort the transaction.
+  const manual = !autoExecute || session.inTransaction;
 
   const { data, error, refetch } = useQuery({
-    queryKey: ["query", code],
-    queryFn: () => fetchQuery(code),
-    enabled: autoExecute,
+    queryKey: ["query", session.id, code],
+    queryFn: async () => {
+      const id = session.id;
+      if (!id) return fetchQuery(code);
+      try {
+        const result = await fetchSessionQuery(id, code);
+        setSession({ type: "SET_TRANSACTION", data: result.in_transaction });
+        return result;
+      } catch (e) {
+        if (e instanceof SessionExpired) {
+          expired(e);
+        } else {
+          // A failed statement can end or abort the transaction
+          fetchSession(id)
+            .then((s) =>
+              setSession({ type: "SET_TRANSACTION", data: s.in_transaction }),
+            )
+            .catch(expired);
+        }
+        throw e;
+      }
+    },
+    enabled: !manual && session.id !== undefined,
     retry: false,
   });
 
+  const transaction = useMutation({
+    mutationFn: (action: "begin" | "commit" | "rollback") =>
+      sendTransaction(session.id!, action),
+    onSuccess: (s) =>
+      setSession({ type: "SET_TRANSACTION", data: s.in_transaction }),
+    onError: expired,
+  });
+
   const grid = !data ? (
-    !autoExecute && code && error ? (
+    manual && code && error ? (
       <Card>
         <CardHeader className="flex items-center">
           <ShieldX className="mb-2 h-12 w-12 text-red-400" />
@@ -122,12 +186,67 @@ function Query() {
               <Terminal className="h-4 w-4" />
             </Toggle>
 
-            {!autoExecute && (
+            {manual && (
               <Button size="sm" onClick={() => refetch()}>
                 <Play className="mr-2 h-4 w-4" /> Execute
               </Button>
             )}
           </div>
+
+          {session.id && (
+            <div className="flex gap-2 items-center">
+              {transaction.error &&
+                !(transaction.error instanceof SessionExpired) && (
+                  <p className="text-destructive text-xs">
+                    The transaction statement failed.
+                  </p>
+                )}
+              {session.rolledBack && (
+                <p
+                  className="text-destructive text-xs"
+                  title="The session sat idle for too long and was closed"
+                >
+                  Session expired, its transaction was rolled back.
+                </p>
+              )}
+
+              {session.inTransaction ? (
+                <>
+                  <Badge
+                    variant="destructive"
+                    title="Changes are only seen by other connections once committed"
+                  >
+                    Open transaction
+                  </Badge>
+                  <Button
+                    size="sm"
+                    variant="outline"
+                    disabled={transaction.isPending}
+                    onClick={() => transaction.mutate("rollback")}
+                  >
+                    <Undo2 className="mr-2 h-4 w-4" /> Rollback
+                  </Button>
+                  <Button
+                    size="sm"
+                    disabled={transaction.isPending}
+                    onClick={() => transaction.mutate("commit")}
+                  >
+                    <Check className="mr-2 h-4 w-4" /> Commit
+                  </Button>
+                </>
+              ) : (
+                <Button
+                  size="sm"
+                  variant="outline"
+                  disabled={transaction.isPending}
+                  onClick={() => transaction.mutate("begin")}
+                  title="Following queries run in a transaction until it's committed or rolled back"
+                >
+                  <Plus className="mr-2 h-4 w-4" /> Begin
+                </Button>
+              )}
+            </div>
+          )}
         </div>
       </div>
 
@@ -1,3 +1,4 @@
+import { z } from "zod";
 import { createFileRoute } from "@tanstack/react-router";
 import { GitBranch } from "lucide-react";
 
@@ -13,7 +14,12 @@ import {
 
 export const Route = createFileRoute("/schema")({
   component: Schema,
-  loader: () => fetchErd(),
+  validateSearch: z.object({
+    tables: z.string().optional(),
+    depth: z.number().int().nonnegative().optional(),
+  }),
+  loaderDeps: ({ search }) => search,
+  loader: ({ deps }) => fetchErd(deps),
   pendingComponent: SchemaSkeleton,
 });
 
@@ -20,9 +20,15 @@ import {
   CardTitle,
 } from "@/components/ui/card";
 import { Badge } from "@/components/ui/badge";
+import { Button } from "@/components/ui/button";
 import { Skeleton } from "@/components/ui/skeleton";
 import { useTheme } from "@/provider/theme.provider";
-import { fetchTable, fetchTableData, fetchTables } from "@/api";
+import {
+  fetchRowCount,
+  fetchTable,
+  fetchTableData,
+  fetchTables,
+} from "@/api";
 import { InfoCard, InfoCardProps } from "@/components/info-card";
 import { Tabs, TabsContent, TabsList, TabsTrigger } from "@/components/ui/tabs";
 
@@ -74,7 +80,8 @@ function Tables() {
           {data.tables.map((n) => (
             <TabsTrigger key={n.name} value={n.name}>
               <Link to="/tables" search={{ table: n.name }}>
-                {n.name} [{n.count.toLocaleString()}]
+                {n.name} [{n.approximate && "~"}
+                {n.count.toLocaleString()}]
               </Link>
             </TabsTrigger>
           ))}
@@ -102,17 +109,42 @@ function Table({ name }: Props) {
     queryKey: ["tables", name],
     queryFn: () => fetchTable(name),
   });
+  const exactCount = useQuery({
+    queryKey: ["tables", name, "count"],
+    queryFn: () => fetchRowCount(name),
+    enabled: false,
+  });
 
   if (!data) return <TableSkeleton />;
 
+  const approximate = data.row_count_approximate && !exactCount.data;
+  const rowCount = exactCount.data?.count ?? data.row_count;
+
   const isVirtual = data.sql?.startsWith("CREATE VIRTUAL TABLE") ?? false;
 
   const cards: InfoCardProps[] = [
     {
       title: "ROW COUNT",
-      value: data.row_count.toLocaleString(),
-      description: "The number of rows in the table.",
+      value: `${approximate ? "~" : ""}${rowCount.toLocaleString()}`,
+      description: approximate
+        ? "Estimated from the planner statistics."
+        : "The number of rows in the table.",
       icon: TableIcon,
+      action: approximate && (
+        <Button
+          variant="link"
+          size="sm"
+          className="h-auto p-0 text-xs"
+          disabled={exactCount.isFetching}
+          onClick={() => exactCount.refetch()}
+        >
+          {exactCount.isError
+            ? "Counting timed out, retry"
+            : exactCount.isFetching
+              ? "Counting..."
+              : "Count exactly"}
+        </Button>
+      ),
     },
     {
       title: "INDEXES",
@@ -149,6 +181,7 @@ function Table({ name }: Props) {
             value={card.value}
             description={card.description}
             icon={card.icon}
+            action={card.action}
           />
         ))}
       </div>